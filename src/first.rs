@@ -1,3 +1,4 @@
+use std::fmt;
 use std::mem;
 struct Node<T> {
     // 
@@ -159,6 +160,74 @@ impl<T> Drop for List<T> {
     }
 }
 
+impl<T> Default for List<T> {
+    fn default() -> Self {
+        List::new()
+    }
+}
+
+impl<T> FromIterator<T> for List<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list = List::new();
+        for item in iter {
+            list.push(item);
+        }
+        list
+    }
+}
+
+impl<T> Extend<T> for List<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for item in iter {
+            self.push(item);
+        }
+    }
+}
+
+impl<T> IntoIterator for List<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> IntoIter<T> {
+        self.into_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a List<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut List<T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> IterMut<'a, T> {
+        self.iter_mut()
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for List<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: Clone> Clone for List<T> {
+    // Node::clone would recurse down the whole chain and blow the stack on
+    // a long list, so rebuild iteratively instead: collect the elements,
+    // reverse them, and push them back on in that order.
+    fn clone(&self) -> Self {
+        let mut elems: Vec<T> = self.iter().cloned().collect();
+        elems.reverse();
+        elems.into_iter().collect()
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::List;
@@ -245,6 +314,29 @@ mod test {
         assert_eq!(iter.next(), Some(&mut 2));
         assert_eq!(iter.next(), Some(&mut 1));
     }
+
+    #[test]
+    fn collect_traits() {
+        let list: List<i32> = (1..=3).collect();
+        assert_eq!(list.peek(), Some(&3));
+
+        let mut list = List::default();
+        list.extend(vec![1, 2, 3]);
+
+        let mut iter = (&list).into_iter();
+        assert_eq!(iter.next(), Some(&3));
+        assert_eq!(iter.next(), Some(&2));
+        assert_eq!(iter.next(), Some(&1));
+
+        let collected: Vec<_> = (&list).into_iter().collect();
+        assert_eq!(collected, vec![&3, &2, &1]);
+
+        let cloned = list.clone();
+        let cloned_vec: Vec<_> = cloned.into_iter().collect();
+        assert_eq!(cloned_vec, vec![3, 2, 1]);
+
+        assert_eq!(format!("{:?}", list), "[3, 2, 1]");
+    }
 }
 fn main() {
     println!("");
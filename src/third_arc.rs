@@ -0,0 +1,127 @@
+use std::sync::Arc;
+
+pub struct List<T> {
+    head: Link<T>
+}
+
+type Link<T> = Option<Arc<Node<T>>>;
+// Arc<T> is the atomically reference-counted sibling of Rc<T>: it uses
+// atomic increments/decrements for its reference count, which costs a
+// little more than Rc's, but in exchange implements `Send`/`Sync` when
+// `T: Send + Sync`, so a List<T> built on it can be shared across threads.
+
+struct Node<T> {
+    elem: T,
+    next: Link<T>
+}
+
+impl<T> List<T> {
+    pub fn new() -> Self {
+        List {head: None}
+    }
+
+    pub fn head(&self) -> Option<&T> {
+        self.head.as_ref().map(|node| &node.elem)
+    }
+
+    pub fn prepend(&self, elem: T) -> List<T> {
+        List { head: Some(Arc::new(Node {
+            elem: elem,
+            next: self.head.clone()
+        }))}
+    }
+
+    pub fn tail(&self) -> List<T> {
+        List { head: self.head.as_ref().and_then(|node| node.next.clone()) }
+    }
+}
+
+pub struct Iter<'a, T> {
+    next: Option<&'a Node<T>>
+}
+
+impl<T> List<T> {
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter { next: self.head.as_deref()}
+    }
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next.map(|node| {
+            self.next = node.next.as_deref();
+            &node.elem
+        })
+    }
+}
+
+impl<T> Clone for List<T> {
+    fn clone(&self) -> Self {
+        List { head: self.head.clone() }
+    }
+}
+
+impl<T> Drop for List<T> {
+    fn drop(&mut self) {
+        let mut head = self.head.take();
+
+        while let Some(node) = head {
+            if let Ok(mut node) = Arc::try_unwrap(node) {
+                head = node.next.take();
+            } else { break; }
+        }
+    }
+}
+#[cfg(test)]
+mod test {
+    use super::List;
+    use std::thread;
+
+    #[test]
+    fn basics() {
+        let list = List::new();
+        assert_eq!(list.head(), None);
+
+        let list = list.prepend(1).prepend(2).prepend(3);
+        assert_eq!(list.head(), Some(&3));
+
+        let list = list.tail();
+        assert_eq!(list.head(), Some(&2));
+
+        let list = list.tail();
+        assert_eq!(list.head(), Some(&1));
+
+        let list = list.tail();
+        assert_eq!(list.head(), None);
+
+        // Make sure empty tail works
+        let list = list.tail();
+        assert_eq!(list.head(), None);
+
+    }
+
+    #[test]
+    fn is_send_and_sync() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<List<i32>>();
+    }
+
+    #[test]
+    fn shared_across_threads() {
+        let list = List::new().prepend(1).prepend(2).prepend(3);
+        let handle_list = list.clone();
+
+        let shared_tail = list.tail();
+
+        let worker = thread::spawn(move || {
+            assert_eq!(handle_list.head(), Some(&3));
+            handle_list.tail()
+        });
+
+        let worker_tail = worker.join().unwrap();
+        assert_eq!(worker_tail.head(), Some(&2));
+        assert_eq!(shared_tail.head(), Some(&2));
+    }
+}